@@ -0,0 +1,166 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign};
+
+use alga::general::Real;
+
+/// An angle, in radians.
+///
+/// Wrapping an angle in `Rad` (or `Deg`) documents its unit at the type level, so rotation
+/// constructors can be generic over `Into<Rad<N>>` and accept either unit without ambiguity.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Rad<N>(pub N);
+
+impl<N: Real> Rad<N> {
+    /// Wraps `angle`, given in radians, as a `Rad`.
+    #[inline]
+    pub fn new(angle: N) -> Self {
+        Rad(angle)
+    }
+
+    /// The sine and cosine of this angle.
+    #[inline]
+    pub fn sin_cos(self) -> (N, N) {
+        self.0.sin_cos()
+    }
+}
+
+/// An angle, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Deg<N>(pub N);
+
+impl<N: Real> Deg<N> {
+    /// Wraps `angle`, given in degrees, as a `Deg`.
+    #[inline]
+    pub fn new(angle: N) -> Self {
+        Deg(angle)
+    }
+
+    /// The sine and cosine of this angle.
+    #[inline]
+    pub fn sin_cos(self) -> (N, N) {
+        Rad::from(self).sin_cos()
+    }
+}
+
+impl<N: Real> From<Deg<N>> for Rad<N> {
+    #[inline]
+    fn from(angle: Deg<N>) -> Self {
+        Rad(angle.0 * N::pi() / ::convert(180.0))
+    }
+}
+
+impl<N: Real> From<Rad<N>> for Deg<N> {
+    #[inline]
+    fn from(angle: Rad<N>) -> Self {
+        Deg(angle.0 * ::convert(180.0) / N::pi())
+    }
+}
+
+// Lets any bare scalar (assumed to already be in radians) be passed wherever an `Into<Rad<N>>`
+// is expected, preserving the crate's existing radian-accepting ergonomics.
+impl<N: Real> From<N> for Rad<N> {
+    #[inline]
+    fn from(angle: N) -> Self {
+        Rad(angle)
+    }
+}
+
+macro_rules! angle_binop_impl(
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+        impl<N: Real> $Op for Rad<N> {
+            type Output = Rad<N>;
+
+            #[inline]
+            fn $op(self, rhs: Rad<N>) -> Rad<N> {
+                Rad(self.0.$op(rhs.0))
+            }
+        }
+
+        impl<N: Real> $OpAssign for Rad<N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Rad<N>) {
+                self.0 = self.0.$op(rhs.0)
+            }
+        }
+
+        impl<N: Real> $Op for Deg<N> {
+            type Output = Deg<N>;
+
+            #[inline]
+            fn $op(self, rhs: Deg<N>) -> Deg<N> {
+                Deg(self.0.$op(rhs.0))
+            }
+        }
+
+        impl<N: Real> $OpAssign for Deg<N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Deg<N>) {
+                self.0 = self.0.$op(rhs.0)
+            }
+        }
+    }
+);
+
+angle_binop_impl!(Add, add, AddAssign, add_assign);
+angle_binop_impl!(Sub, sub, SubAssign, sub_assign);
+
+impl<N: Real> Mul<N> for Rad<N> {
+    type Output = Rad<N>;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Rad<N> {
+        Rad(self.0 * rhs)
+    }
+}
+
+impl<N: Real> MulAssign<N> for Rad<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        self.0 *= rhs
+    }
+}
+
+impl<N: Real> Mul<N> for Deg<N> {
+    type Output = Deg<N>;
+
+    #[inline]
+    fn mul(self, rhs: N) -> Deg<N> {
+        Deg(self.0 * rhs)
+    }
+}
+
+impl<N: Real> MulAssign<N> for Deg<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: N) {
+        self.0 *= rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{MatrixArray, Unit, Vector3};
+    use core::dimension::U3;
+    use geometry::RotationBase;
+    use geometry::angle::{Deg, Rad};
+
+    #[test]
+    fn deg_rad_conversion_factor() {
+        let half_turn: Rad<f64> = Deg(180.0).into();
+        assert!((half_turn.0 - ::std::f64::consts::PI).abs() < 1.0e-10);
+
+        let full_turn: Deg<f64> = Rad(2.0 * ::std::f64::consts::PI).into();
+        assert!((full_turn.0 - 360.0).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn from_axis_angle_degrees_matches_radians() {
+        type Rotation3 = RotationBase<f64, U3, MatrixArray<f64, U3, U3>>;
+
+        let axis = Unit::new_normalize(Vector3::new(0.0, 0.0, 1.0));
+        let from_degrees = Rotation3::from_axis_angle(&axis, Deg(90.0));
+        let from_radians = Rotation3::from_axis_angle(&axis, ::std::f64::consts::FRAC_PI_2);
+
+        for (a, b) in from_degrees.matrix().iter().zip(from_radians.matrix().iter()) {
+            assert!((a - b).abs() < 1.0e-10);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod rotation_specialization;
+pub mod angle;
+
+pub use self::angle::{Deg, Rad};
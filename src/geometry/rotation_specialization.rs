@@ -12,6 +12,7 @@ use core::storage::{Storage, OwnedStorage};
 use core::allocator::{Allocator, OwnedAllocator};
 
 use geometry::{RotationBase, OwnedRotation, UnitComplex};
+use geometry::angle::Rad;
 
 
 /*
@@ -23,9 +24,10 @@ impl<N, S> RotationBase<N, U2, S>
 where N: Real,
       S: OwnedStorage<N, U2, U2>,
       S::Alloc: OwnedAllocator<N, U2, U2, S> {
-    /// Builds a 2 dimensional rotation matrix from an angle in radian.
-    pub fn new(angle: N) -> Self {
-        let (sia, coa) = angle.sin_cos();
+    /// Builds a 2 dimensional rotation matrix from an angle, in radians by default (pass a
+    /// `Deg` to build from degrees instead).
+    pub fn new<A: Into<Rad<N>>>(angle: A) -> Self {
+        let (sia, coa) = angle.into().sin_cos();
         Self::from_matrix_unchecked(SquareMatrix::<N, U2, S>::new(coa, -sia, sia, coa))
     }
 
@@ -37,6 +39,45 @@ where N: Real,
         Self::new(axisangle[0])
     }
 
+    /// Creates a new rotation that corresponds to the local frame of an observer standing at the
+    /// origin and looking toward `dir`.
+    ///
+    /// It maps the positive `x` axis to the view direction `dir`.
+    ///
+    /// # Arguments
+    ///   * dir - The look direction, that is, direction the matrix `x` axis will be aligned with.
+    #[inline]
+    pub fn new_observer_frame<SB>(dir: &ColumnVector<N, U2, SB>) -> Self
+        where SB: Storage<N, U2, U1> {
+        Self::new(dir.y.atan2(dir.x))
+    }
+
+    /// Builds a right-handed look-at view matrix without translation, for 2D.
+    ///
+    /// This conforms to the common notion of right handed look-at matrix from the computer
+    /// graphics community.
+    ///
+    /// # Arguments
+    ///   * dir - The look direction. The resulting rotation maps it to the negative `x` axis.
+    #[inline]
+    pub fn look_at_rh<SB>(dir: &ColumnVector<N, U2, SB>) -> Self
+        where SB: Storage<N, U2, U1> {
+        Self::new_observer_frame(&dir.neg()).inverse()
+    }
+
+    /// Builds a left-handed look-at view matrix without translation, for 2D.
+    ///
+    /// This conforms to the common notion of left handed look-at matrix from the computer
+    /// graphics community.
+    ///
+    /// # Arguments
+    ///   * dir - The look direction, that is, direction the matrix `x` axis will be aligned with.
+    #[inline]
+    pub fn look_at_lh<SB>(dir: &ColumnVector<N, U2, SB>) -> Self
+        where SB: Storage<N, U2, U1> {
+        Self::new_observer_frame(dir).inverse()
+    }
+
     /// The rotation matrix required to align `a` and `b` but with its angl.
     ///
     /// This is the rotation `R` such that `(R * a).angle(b) == 0 && (R * a).dot(b).is_positive()`.
@@ -62,13 +103,13 @@ where N: Real,
       S: Storage<N, U2, U2> {
     /// The rotation angle.
     #[inline]
-    pub fn angle(&self) -> N {
-        self.matrix()[(1, 0)].atan2(self.matrix()[(0, 0)])
+    pub fn angle(&self) -> Rad<N> {
+        Rad(self.matrix()[(1, 0)].atan2(self.matrix()[(0, 0)]))
     }
 
     /// The rotation angle needed to make `self` and `other` coincide.
     #[inline]
-    pub fn angle_to<SB: Storage<N, U2, U2>>(&self, other: &RotationBase<N, U2, SB>) -> N {
+    pub fn angle_to<SB: Storage<N, U2, U2>>(&self, other: &RotationBase<N, U2, SB>) -> Rad<N> {
         self.rotation_to(other).angle()
     }
 
@@ -92,7 +133,7 @@ where N: Real,
     #[inline]
     pub fn scaled_axis(&self) -> OwnedColumnVector<N, U1, S::Alloc>
         where S::Alloc: Allocator<N, U1, U1> {
-        ColumnVector::<_, U1, _>::new(self.angle())
+        ColumnVector::<_, U1, _>::new(self.angle().0)
     }
 }
 
@@ -143,9 +184,13 @@ where N: Real,
         Self::new(axisangle)
     }
 
-    /// Builds a 3D rotation matrix from an axis and a rotation angle.
-    pub fn from_axis_angle<SB>(axis: &Unit<ColumnVector<N, U3, SB>>, angle: N) -> Self
-        where SB: Storage<N, U3, U1> {
+    /// Builds a 3D rotation matrix from an axis and a rotation angle, in radians by default
+    /// (pass a `Deg` to build from degrees instead).
+    pub fn from_axis_angle<SB, A>(axis: &Unit<ColumnVector<N, U3, SB>>, angle: A) -> Self
+        where SB: Storage<N, U3, U1>,
+              A: Into<Rad<N>> {
+        let angle = angle.into().0;
+
         if angle.is_zero() {
             Self::identity()
         }
@@ -175,13 +220,50 @@ where N: Real,
         }
     }
 
-    /// Creates a new rotation from Euler angles.
+    /// Creates a rotation that corresponds to a rotation of `angle` about the `x` axis.
+    #[inline]
+    pub fn from_angle_x<A: Into<Rad<N>>>(angle: A) -> Self {
+        let (s, c) = angle.into().sin_cos();
+        Self::from_matrix_unchecked(
+            SquareMatrix::<N, U3, S>::new(
+                N::one(), N::zero(), N::zero(),
+                N::zero(), c,        -s,
+                N::zero(), s,        c))
+    }
+
+    /// Creates a rotation that corresponds to a rotation of `angle` about the `y` axis.
+    #[inline]
+    pub fn from_angle_y<A: Into<Rad<N>>>(angle: A) -> Self {
+        let (s, c) = angle.into().sin_cos();
+        Self::from_matrix_unchecked(
+            SquareMatrix::<N, U3, S>::new(
+                c,         N::zero(), s,
+                N::zero(), N::one(),  N::zero(),
+                -s,        N::zero(), c))
+    }
+
+    /// Creates a rotation that corresponds to a rotation of `angle` about the `z` axis.
+    #[inline]
+    pub fn from_angle_z<A: Into<Rad<N>>>(angle: A) -> Self {
+        let (s, c) = angle.into().sin_cos();
+        Self::from_matrix_unchecked(
+            SquareMatrix::<N, U3, S>::new(
+                c,         -s,        N::zero(),
+                s,         c,         N::zero(),
+                N::zero(), N::zero(), N::one()))
+    }
+
+    /// Creates a new rotation from Euler angles, in radians by default (pass `Deg` values to
+    /// build from degrees instead).
     ///
     /// The primitive rotations are applied in order: 1 roll − 2 pitch − 3 yaw.
-    pub fn from_euler_angles(roll: N, pitch: N, yaw: N) -> Self {
-        let (sr, cr) = roll.sin_cos();
-        let (sp, cp) = pitch.sin_cos();
-        let (sy, cy) = yaw.sin_cos();
+    pub fn from_euler_angles<A1, A2, A3>(roll: A1, pitch: A2, yaw: A3) -> Self
+        where A1: Into<Rad<N>>,
+              A2: Into<Rad<N>>,
+              A3: Into<Rad<N>> {
+        let (sr, cr) = roll.into().sin_cos();
+        let (sp, cp) = pitch.into().sin_cos();
+        let (sy, cy) = yaw.into().sin_cos();
 
         Self::from_matrix_unchecked(
             SquareMatrix::<N, U3, S>::new(
@@ -294,8 +376,33 @@ where N: Real,
       S: Storage<N, U3, U3> {
     /// The rotation angle.
     #[inline]
-    pub fn angle(&self) -> N {
-        ((self.matrix()[(0, 0)] + self.matrix()[(1, 1)] + self.matrix()[(2, 2)] - N::one()) / ::convert(2.0)).acos()
+    pub fn angle(&self) -> Rad<N> {
+        Rad(((self.matrix()[(0, 0)] + self.matrix()[(1, 1)] + self.matrix()[(2, 2)] - N::one()) / ::convert(2.0)).acos())
+    }
+
+    /// Euler angles corresponding to this rotation, as the `(roll, pitch, yaw)` triple fed to
+    /// `from_euler_angles`.
+    pub fn to_euler_angles(&self) -> (N, N, N) {
+        // Gimbal lock: `pitch` is at ±π/2 so `roll` and `yaw` rotate about the same axis and
+        // can't be told apart; we pin `roll` to zero and fold its contribution into `yaw`.
+        if self.matrix()[(2, 0)].abs() >= N::one() - N::default_epsilon() {
+            let yaw   = (-self.matrix()[(0, 1)]).atan2(self.matrix()[(1, 1)]);
+            let pitch = if self.matrix()[(2, 0)] < N::zero() {
+                N::frac_pi_2()
+            }
+            else {
+                -N::frac_pi_2()
+            };
+
+            (N::zero(), pitch, yaw)
+        }
+        else {
+            let roll  = self.matrix()[(2, 1)].atan2(self.matrix()[(2, 2)]);
+            let pitch = (-self.matrix()[(2, 0)]).asin();
+            let yaw   = self.matrix()[(1, 0)].atan2(self.matrix()[(0, 0)]);
+
+            (roll, pitch, yaw)
+        }
     }
 }
 
@@ -318,7 +425,7 @@ where N: Real,
     #[inline]
     pub fn scaled_axis(&self) -> OwnedColumnVector<N, U3, S::Alloc> {
         if let Some(axis) = self.axis() {
-            axis.unwrap() * self.angle()
+            axis.unwrap() * self.angle().0
         }
         else {
             ColumnVector::zero()
@@ -327,7 +434,7 @@ where N: Real,
 
     /// The rotation angle needed to make `self` and `other` coincide.
     #[inline]
-    pub fn angle_to<SB: Storage<N, U3, U3>>(&self, other: &RotationBase<N, U3, SB>) -> N {
+    pub fn angle_to<SB: Storage<N, U3, U3>>(&self, other: &RotationBase<N, U3, SB>) -> Rad<N> {
         self.rotation_to(other).angle()
     }
 
@@ -379,3 +486,37 @@ where N: Real + Arbitrary,
         Self::new(Vector3::arbitrary(g))
     }
 }
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod tests {
+    use quickcheck::{Arbitrary, Gen};
+
+    use core::MatrixArray;
+    use core::dimension::U3;
+    use geometry::RotationBase;
+
+    type Rotation3<N> = RotationBase<N, U3, MatrixArray<N, U3, U3>>;
+
+    /// An angle, in radians, kept away from the extreme magnitudes `Arbitrary` would otherwise
+    /// generate for `f64` — huge angles lose range-reduction precision in `sin_cos`, which would
+    /// make the round-trip comparison below flaky rather than a reliable check.
+    #[derive(Clone, Copy, Debug)]
+    struct SmallAngle(f64);
+
+    impl Arbitrary for SmallAngle {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            SmallAngle(g.gen_range(-1.0e3, 1.0e3))
+        }
+    }
+
+    quickcheck! {
+        fn euler_angles_roundtrip(roll: SmallAngle, pitch: SmallAngle, yaw: SmallAngle) -> bool {
+            let rot      = Rotation3::from_euler_angles(roll.0, pitch.0, yaw.0);
+            let (r, p, y) = rot.to_euler_angles();
+            let rebuilt  = Rotation3::from_euler_angles(r, p, y);
+
+            rot.matrix().iter().zip(rebuilt.matrix().iter())
+               .all(|(a, b)| (a - b).abs() < 1.0e-7)
+        }
+    }
+}